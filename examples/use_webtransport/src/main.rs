@@ -4,10 +4,27 @@ use leptos_use::use_webtransport;
 
 #[component]
 fn Demo() -> impl IntoView {
+    let transport = use_webtransport("https://example.com:4433");
 
-    use_webtransport();
+    let send_ping = {
+        let transport = transport.clone();
+        move |_| (transport.send_datagram)(b"ping")
+    };
 
-    view! {  }
+    view! {
+        <p>"ready_state: " {move || format!("{:?}", transport.ready_state.get())}</p>
+        <p>
+            "latest_datagram: "
+            {move || {
+                transport
+                    .latest_datagram
+                    .get()
+                    .map(|bytes| String::from_utf8_lossy(&bytes).to_string())
+                    .unwrap_or_default()
+            }}
+        </p>
+        <button on:click=send_ping>"Send datagram"</button>
+    }
 }
 
 fn main() {
@@ -17,4 +34,4 @@ fn main() {
     mount_to(demo_or_body(), || {
         view! { <Demo/> }
     })
-}
\ No newline at end of file
+}
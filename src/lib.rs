@@ -0,0 +1,13 @@
+//! Collection of essential Leptos utilities based on `@vueuse/core`.
+
+mod sync_signal;
+pub use sync_signal::*;
+
+mod sync_signal_over_transport;
+pub use sync_signal_over_transport::*;
+
+mod use_webtransport;
+pub use use_webtransport::*;
+
+mod use_shared_state;
+pub use use_shared_state::*;
@@ -1,7 +1,10 @@
 use crate::core::UseRwSignal;
 use default_struct_builder::DefaultBuilder;
 use leptos::*;
+use std::cell::{Cell, RefCell};
 use std::fmt::Debug;
+use std::future::Future;
+use std::pin::Pin;
 use std::rc::Rc;
 
 /// Two-way Signals synchronization.
@@ -151,22 +154,94 @@ where
         direction,
         transform_ltr,
         transform_rtl,
+        on_error,
+        conflict_policy,
     } = options;
 
     let left = left.into();
     let right = right.into();
 
+    // Only `Both` can race - a single direction has nothing to conflict with.
+    let guard_conflicts = matches!(direction, SyncDirection::Both);
+
+    // Per-signal version counters, bumped once per genuinely independent change (never for
+    // a watch firing that only mirrors the other side's own propagation). Compared *before*
+    // this tick's bump so neither watcher privileges itself just by being the one currently
+    // running - see `resolve_conflict`.
+    let left_version = Rc::new(Cell::new(0u64));
+    let right_version = Rc::new(Cell::new(0u64));
+
+    // Set while a side is being written to as a result of the *other* side's change, so that
+    // side's own watch can tell "I fired because I was just mirrored into" apart from "I
+    // fired because the user genuinely changed me".
+    let propagating_to_left = Rc::new(Cell::new(false));
+    let propagating_to_right = Rc::new(Cell::new(false));
+
+    // The last value each side observed of the other. A conflict is real only when the
+    // other side's current value has drifted from this - an ordinary one-directional edit
+    // never does, so it always falls through to normal propagation.
+    let left_known_right = Rc::new(RefCell::new(right.with_untracked(|right| right.clone())));
+    let right_known_left = Rc::new(RefCell::new(left.with_untracked(|left| left.clone())));
+
     let mut stop_watch_left = None;
     let mut stop_watch_right = None;
 
     if matches!(direction, SyncDirection::Both | SyncDirection::LeftToRight) {
+        let on_error = Rc::clone(&on_error);
+        let conflict_policy = conflict_policy.clone();
+        let left_version = Rc::clone(&left_version);
+        let right_version = Rc::clone(&right_version);
+        let propagating_to_left = Rc::clone(&propagating_to_left);
+        let propagating_to_right = Rc::clone(&propagating_to_right);
+        let left_known_right = Rc::clone(&left_known_right);
+        let right_known_left = Rc::clone(&right_known_left);
+
         stop_watch_left = Some(watch(
             move || left.get(),
             move |new_value, _, _| {
-                let new_value = (*transform_ltr)(new_value);
+                // This firing is just the echo of our own right-to-left write, not a new
+                // change on this side - nothing to arbitrate or re-propagate.
+                if propagating_to_left.get() {
+                    *right_known_left.borrow_mut() = new_value.clone();
+                    return;
+                }
+
+                let right_value = right.with_untracked(|right| right.clone());
+                let is_conflict = guard_conflicts && right_value != *left_known_right.borrow();
+
+                let winner = if is_conflict {
+                    resolve_conflict(
+                        &conflict_policy,
+                        Side::Left,
+                        new_value,
+                        &right_value,
+                        left_version.get(),
+                        right_version.get(),
+                    )
+                } else {
+                    Side::Left
+                };
+
+                left_version.set(left_version.get() + 1);
+                *left_known_right.borrow_mut() = right_value;
+
+                if winner == Side::Left {
+                    propagating_to_right.set(true);
 
-                if right.with_untracked(|right| right != &new_value) {
-                    right.update(|right| *right = new_value);
+                    let propagating_to_right = Rc::clone(&propagating_to_right);
+                    let left_known_right = Rc::clone(&left_known_right);
+                    let right_known_left = Rc::clone(&right_known_left);
+
+                    apply_transform(&transform_ltr, new_value, right, &on_error, move || {
+                        // Only lifted once the value has actually landed on `right` - for
+                        // an async transform that's after the spawned future resolves, not
+                        // right after it's kicked off, so `right`'s own watch can't mistake
+                        // the eventual write for a genuine right-side edit.
+                        propagating_to_right.set(false);
+                        *left_known_right.borrow_mut() =
+                            right.with_untracked(|right| right.clone());
+                        *right_known_left.borrow_mut() = left.with_untracked(|left| left.clone());
+                    });
                 }
             },
             immediate,
@@ -174,13 +249,57 @@ where
     }
 
     if matches!(direction, SyncDirection::Both | SyncDirection::RightToLeft) {
+        let propagating_to_left = Rc::clone(&propagating_to_left);
+        let propagating_to_right = Rc::clone(&propagating_to_right);
+        let left_known_right = Rc::clone(&left_known_right);
+        let right_known_left = Rc::clone(&right_known_left);
+
         stop_watch_right = Some(watch(
             move || right.get(),
             move |new_value, _, _| {
-                let new_value = (*transform_rtl)(new_value);
+                // This firing is just the echo of our own left-to-right write, not a new
+                // change on this side - nothing to arbitrate or re-propagate.
+                if propagating_to_right.get() {
+                    *left_known_right.borrow_mut() = new_value.clone();
+                    return;
+                }
+
+                let left_value = left.with_untracked(|left| left.clone());
+                let is_conflict = guard_conflicts && left_value != *right_known_left.borrow();
+
+                let winner = if is_conflict {
+                    resolve_conflict(
+                        &conflict_policy,
+                        Side::Right,
+                        &left_value,
+                        new_value,
+                        left_version.get(),
+                        right_version.get(),
+                    )
+                } else {
+                    Side::Right
+                };
 
-                if left.with_untracked(|left| left != &new_value) {
-                    left.update(|left| *left = new_value);
+                right_version.set(right_version.get() + 1);
+                *right_known_left.borrow_mut() = left_value;
+
+                if winner == Side::Right {
+                    propagating_to_left.set(true);
+
+                    let propagating_to_left = Rc::clone(&propagating_to_left);
+                    let right_known_left = Rc::clone(&right_known_left);
+                    let left_known_right = Rc::clone(&left_known_right);
+
+                    apply_transform(&transform_rtl, new_value, left, &on_error, move || {
+                        // Only lifted once the value has actually landed on `left` - for an
+                        // async transform that's after the spawned future resolves, not
+                        // right after it's kicked off, so `left`'s own watch can't mistake
+                        // the eventual write for a genuine left-side edit.
+                        propagating_to_left.set(false);
+                        *right_known_left.borrow_mut() = left.with_untracked(|left| left.clone());
+                        *left_known_right.borrow_mut() =
+                            right.with_untracked(|right| right.clone());
+                    });
                 }
             },
             immediate,
@@ -197,6 +316,195 @@ where
     }
 }
 
+/// Which side of a [`sync_signal`] pair a conflict should resolve to. Also used to mark
+/// which side initiated the propagation being arbitrated.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Side {
+    /// The left signal.
+    Left,
+    /// The right signal.
+    Right,
+}
+
+/// Only called once a race has already been detected (the other side drifted from what
+/// this side last knew about it) - an ordinary one-directional edit never reaches here, so
+/// `PreferLeft`/`PreferRight` don't clobber changes the other side never raced with.
+fn resolve_conflict<L, R>(
+    policy: &SyncConflictPolicy<L, R>,
+    initiator: Side,
+    left_value: &L,
+    right_value: &R,
+    left_version: u64,
+    right_version: u64,
+) -> Side {
+    match policy {
+        SyncConflictPolicy::Unguarded => initiator,
+        SyncConflictPolicy::PreferLeft => Side::Left,
+        SyncConflictPolicy::PreferRight => Side::Right,
+        SyncConflictPolicy::Newest => {
+            if right_version > left_version {
+                Side::Right
+            } else {
+                Side::Left
+            }
+        }
+        SyncConflictPolicy::Custom(resolve) => resolve(left_value, right_value),
+    }
+}
+
+/// Runs a [`SyncTransform`] on `value` and, once resolved, applies the result to `target`
+/// through the same `with_untracked`/`update` echo-guard every transform kind shares.
+///
+/// `on_applied` fires exactly once the result has actually been resolved (applied or
+/// errored) - for [`SyncTransform::Async`] that's after the spawned future completes, not
+/// when it's merely kicked off, so callers can rely on it to know the write has truly
+/// landed (or been given up on) before treating the propagation as finished.
+fn apply_transform<From, To>(
+    transform: &SyncTransform<From, To>,
+    value: &From,
+    target: UseRwSignal<To>,
+    on_error: &Rc<dyn Fn(String)>,
+    on_applied: impl FnOnce() + 'static,
+) where
+    From: Clone + 'static,
+    To: Clone + PartialEq + 'static,
+{
+    match transform {
+        SyncTransform::Sync(transform) => {
+            apply_to_target(target, transform(value));
+            on_applied();
+        }
+        SyncTransform::Fallible(transform) => {
+            match transform(value) {
+                Ok(new_value) => apply_to_target(target, new_value),
+                Err(err) => on_error(err),
+            }
+            on_applied();
+        }
+        SyncTransform::Async(transform) => {
+            let future = transform(value);
+
+            spawn_local(async move {
+                let new_value = future.await;
+                apply_to_target(target, new_value);
+                on_applied();
+            });
+        }
+    }
+}
+
+fn apply_to_target<To>(target: UseRwSignal<To>, new_value: To)
+where
+    To: Clone + PartialEq + 'static,
+{
+    if target.with_untracked(|target| target != &new_value) {
+        target.update(|target| *target = new_value);
+    }
+}
+
+/// Like [`sync_signal`] but for a read-only `source` (a derived [`Signal`], a [`Memo`],
+/// or a plain `Fn() -> L`) piped one-way into a settable `target` (a bare
+/// [`SignalSetter`], a closure, or anything else `Into<SignalSetter<R>>`).
+///
+/// Because the source can't be written to, this is always left-to-right and there is no
+/// echo guard to worry about: the `target` is only ever written from the `source`.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::sync_signal_from;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let (count, set_count) = create_signal(1);
+/// let doubled = Signal::derive(move || count.get() * 2);
+///
+/// let (external, set_external) = create_signal(0);
+///
+/// let stop = sync_signal_from(doubled, SignalSetter::map(move |value| set_external.set(value)));
+///
+/// set_count.set(5);
+///
+/// logging::log!("external: {}", external.get()); // external: 10
+/// #
+/// # view! { }
+/// # }
+/// ```
+pub fn sync_signal_from<L, R>(
+    source: impl Into<Signal<L>>,
+    target: impl Into<SignalSetter<R>>,
+) -> impl Fn() + Clone
+where
+    L: Clone + PartialEq + 'static,
+    R: Clone + PartialEq + 'static,
+{
+    sync_signal_from_with_options(source, target, SyncSignalFromOptions::default())
+}
+
+/// Version of [`sync_signal_from`] that takes a `SyncSignalFromOptions`. See
+/// [`sync_signal_from`] for how to use.
+pub fn sync_signal_from_with_options<L, R>(
+    source: impl Into<Signal<L>>,
+    target: impl Into<SignalSetter<R>>,
+    options: SyncSignalFromOptions<L, R>,
+) -> impl Fn() + Clone
+where
+    L: Clone + PartialEq + 'static,
+    R: Clone + PartialEq + 'static,
+{
+    let SyncSignalFromOptions {
+        immediate,
+        transform,
+    } = options;
+
+    let source = source.into();
+    let target = target.into();
+
+    let stop_watch = watch(
+        move || source.get(),
+        move |new_value, _, _| {
+            target.set((*transform)(new_value));
+        },
+        immediate,
+    );
+
+    move || {
+        stop_watch();
+    }
+}
+
+/// Options for [`sync_signal_from_with_options`].
+#[derive(DefaultBuilder)]
+pub struct SyncSignalFromOptions<L, R> {
+    /// If `true`, `target` is immediately synced with `source`'s current value when this
+    /// function is called. Defaults to `true`.
+    immediate: bool,
+
+    /// Transforms the source value into the target value. Defaults to identity.
+    #[builder(skip)]
+    transform: Rc<dyn Fn(&L) -> R>,
+}
+
+impl<L, R> SyncSignalFromOptions<L, R> {
+    /// Transforms the source value into the target value. Defaults to identity.
+    pub fn transform(self, transform: impl Fn(&L) -> R + 'static) -> Self {
+        Self {
+            transform: Rc::new(transform),
+            ..self
+        }
+    }
+}
+
+impl<T: Clone> Default for SyncSignalFromOptions<T, T> {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            transform: Rc::new(|x| x.clone()),
+        }
+    }
+}
+
 /// Direction of syncing.
 pub enum SyncDirection {
     LeftToRight,
@@ -204,6 +512,64 @@ pub enum SyncDirection {
     Both,
 }
 
+/// How a signal's value is carried over to the other side of a [`sync_signal`] pair.
+enum SyncTransform<From, To> {
+    /// Always succeeds and runs synchronously. This is the default (identity).
+    Sync(Rc<dyn Fn(&From) -> To>),
+
+    /// May fail; on `Err` the update is skipped and the error is handed to
+    /// [`SyncSignalOptions`]'s `on_error` callback instead of being applied.
+    Fallible(Rc<dyn Fn(&From) -> Result<To, String>>),
+
+    /// Resolves later; the target is only updated once the future completes, through the
+    /// same echo-guard every other transform kind goes through.
+    Async(Rc<dyn Fn(&From) -> Pin<Box<dyn Future<Output = To>>>>),
+}
+
+impl<From, To> Clone for SyncTransform<From, To> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Sync(transform) => Self::Sync(Rc::clone(transform)),
+            Self::Fallible(transform) => Self::Fallible(Rc::clone(transform)),
+            Self::Async(transform) => Self::Async(Rc::clone(transform)),
+        }
+    }
+}
+
+/// How [`sync_signal_with_options`] picks a winner when both signals of a
+/// `SyncDirection::Both` pair change in the same tick.
+pub enum SyncConflictPolicy<L, R> {
+    /// No arbitration: whichever watch callback happens to run last wins, same as before
+    /// this policy existed. This is the default.
+    Unguarded,
+
+    /// The left signal always wins.
+    PreferLeft,
+
+    /// The right signal always wins.
+    PreferRight,
+
+    /// The side with the higher per-signal update counter (the number of its own changes,
+    /// not counting values mirrored over from the other side) wins; on a tie the left side
+    /// wins. This compares change counts, not when each side last changed.
+    Newest,
+
+    /// A user-supplied function picks the winner given both current values.
+    Custom(Rc<dyn Fn(&L, &R) -> Side>),
+}
+
+impl<L, R> Clone for SyncConflictPolicy<L, R> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::Unguarded => Self::Unguarded,
+            Self::PreferLeft => Self::PreferLeft,
+            Self::PreferRight => Self::PreferRight,
+            Self::Newest => Self::Newest,
+            Self::Custom(resolve) => Self::Custom(Rc::clone(resolve)),
+        }
+    }
+}
+
 /// Options for [`sync_signal_with_options`].
 #[derive(DefaultBuilder)]
 pub struct SyncSignalOptions<L, R> {
@@ -218,20 +584,33 @@ pub struct SyncSignalOptions<L, R> {
     /// Transforms the left signal into the right signal.
     /// Defaults to identity.
     #[builder(skip)]
-    transform_ltr: Rc<dyn Fn(&L) -> R>,
+    transform_ltr: SyncTransform<L, R>,
 
     /// Transforms the right signal into the left signal.
     /// Defaults to identity.
     #[builder(skip)]
-    transform_rtl: Rc<dyn Fn(&R) -> L>,
+    transform_rtl: SyncTransform<R, L>,
+
+    /// Called when a fallible transform returns `Err`. Defaults to a no-op.
+    #[builder(skip)]
+    on_error: Rc<dyn Fn(String)>,
+
+    /// How to resolve a `SyncDirection::Both` pair changing in the same tick.
+    /// Defaults to [`SyncConflictPolicy::Unguarded`].
+    #[builder(skip)]
+    conflict_policy: SyncConflictPolicy<L, R>,
 }
 
-impl<L, R> SyncSignalOptions<L, R> {
+impl<L, R> SyncSignalOptions<L, R>
+where
+    L: 'static,
+    R: 'static,
+{
     /// Transforms the left signal into the right signal.
     /// Defaults to identity.
     pub fn transform_ltr(self, transform_ltr: impl Fn(&L) -> R + 'static) -> Self {
         Self {
-            transform_ltr: Rc::new(transform_ltr),
+            transform_ltr: SyncTransform::Sync(Rc::new(transform_ltr)),
             ..self
         }
     }
@@ -240,19 +619,90 @@ impl<L, R> SyncSignalOptions<L, R> {
     /// Defaults to identity.
     pub fn transform_rtl(self, transform_rtl: impl Fn(&R) -> L + 'static) -> Self {
         Self {
-            transform_rtl: Rc::new(transform_rtl),
+            transform_rtl: SyncTransform::Sync(Rc::new(transform_rtl)),
+            ..self
+        }
+    }
+
+    /// Transforms the left signal into the right signal, skipping the update and calling
+    /// `on_error` if it returns `Err`.
+    pub fn transform_ltr_fallible(
+        self,
+        transform_ltr: impl Fn(&L) -> Result<R, String> + 'static,
+    ) -> Self {
+        Self {
+            transform_ltr: SyncTransform::Fallible(Rc::new(transform_ltr)),
+            ..self
+        }
+    }
+
+    /// Transforms the right signal into the left signal, skipping the update and calling
+    /// `on_error` if it returns `Err`.
+    pub fn transform_rtl_fallible(
+        self,
+        transform_rtl: impl Fn(&R) -> Result<L, String> + 'static,
+    ) -> Self {
+        Self {
+            transform_rtl: SyncTransform::Fallible(Rc::new(transform_rtl)),
+            ..self
+        }
+    }
+
+    /// Transforms the left signal into the right signal asynchronously. The right signal
+    /// is updated once the returned future resolves, via `leptos`'s task spawning.
+    pub fn transform_ltr_async<Fut>(self, transform_ltr: impl Fn(&L) -> Fut + 'static) -> Self
+    where
+        Fut: Future<Output = R> + 'static,
+    {
+        Self {
+            transform_ltr: SyncTransform::Async(Rc::new(move |value| {
+                Box::pin(transform_ltr(value))
+            })),
+            ..self
+        }
+    }
+
+    /// Transforms the right signal into the left signal asynchronously. The left signal
+    /// is updated once the returned future resolves, via `leptos`'s task spawning.
+    pub fn transform_rtl_async<Fut>(self, transform_rtl: impl Fn(&R) -> Fut + 'static) -> Self
+    where
+        Fut: Future<Output = L> + 'static,
+    {
+        Self {
+            transform_rtl: SyncTransform::Async(Rc::new(move |value| {
+                Box::pin(transform_rtl(value))
+            })),
+            ..self
+        }
+    }
+
+    /// Called when a fallible transform returns `Err`. Defaults to a no-op.
+    pub fn on_error(self, on_error: impl Fn(String) + 'static) -> Self {
+        Self {
+            on_error: Rc::new(on_error),
+            ..self
+        }
+    }
+
+    /// How to resolve a `SyncDirection::Both` pair changing in the same tick.
+    /// Defaults to [`SyncConflictPolicy::Unguarded`].
+    pub fn conflict_policy(self, conflict_policy: SyncConflictPolicy<L, R>) -> Self {
+        Self {
+            conflict_policy,
             ..self
         }
     }
 }
 
-impl<T: Clone> Default for SyncSignalOptions<T, T> {
+impl<T: Clone + 'static> Default for SyncSignalOptions<T, T> {
     fn default() -> Self {
         Self {
             immediate: true,
             direction: SyncDirection::Both,
-            transform_ltr: Rc::new(|x| x.clone()),
-            transform_rtl: Rc::new(|x| x.clone()),
+            transform_ltr: SyncTransform::Sync(Rc::new(|x| x.clone())),
+            transform_rtl: SyncTransform::Sync(Rc::new(|x| x.clone())),
+            on_error: Rc::new(|_| {}),
+            conflict_policy: SyncConflictPolicy::Unguarded,
         }
     }
 }
@@ -0,0 +1,170 @@
+use crate::core::{ConnectionReadyState, UseRwSignal};
+use crate::{use_webtransport, UseWebTransportReturn};
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Keeps a local signal in sync with a remote peer's signal over a WebTransport
+/// connection, reusing the same watch machinery as [`sync_signal`].
+///
+/// This opens its own WebTransport session to `url` through [`use_webtransport`]; every
+/// call dials a separate session.
+///
+/// Every local change is serialized with `serde` and sent as a WebTransport datagram.
+/// Every inbound datagram is deserialized and applied with the same `with_untracked`/
+/// `update` value check [`sync_signal_with_options`] uses, and the outbound watch is
+/// suppressed while an inbound datagram is being applied, so a value that was just applied
+/// from the peer doesn't get immediately re-sent back to it.
+///
+/// This lets you keep e.g. a form field or a cursor position mirrored across
+/// browser tabs/clients without hand-writing the message plumbing.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::sync_signal_over_transport;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let cursor = create_rw_signal((0.0, 0.0));
+///
+/// let stop = sync_signal_over_transport("https://example.com/sync", cursor);
+/// #
+/// # view! { }
+/// # }
+/// ```
+pub fn sync_signal_over_transport<T>(
+    url: &str,
+    signal: impl Into<UseRwSignal<T>>,
+) -> impl Fn() + Clone
+where
+    T: Serialize + DeserializeOwned + Clone + PartialEq + 'static,
+{
+    sync_signal_over_transport_with_options(url, signal, SyncSignalOverTransportOptions::default())
+}
+
+/// Version of [`sync_signal_over_transport`] that takes a `SyncSignalOverTransportOptions`.
+/// See [`sync_signal_over_transport`] for how to use.
+pub fn sync_signal_over_transport_with_options<T>(
+    url: &str,
+    signal: impl Into<UseRwSignal<T>>,
+    options: SyncSignalOverTransportOptions,
+) -> impl Fn() + Clone
+where
+    T: Serialize + DeserializeOwned + Clone + PartialEq + 'static,
+{
+    let SyncSignalOverTransportOptions {
+        immediate,
+        on_error,
+    } = options;
+
+    let signal = signal.into();
+
+    let UseWebTransportReturn {
+        ready_state,
+        send_datagram,
+        latest_datagram,
+        ..
+    } = use_webtransport(url);
+
+    // Set while an inbound datagram is being applied to `signal`, so the outbound watch
+    // below can tell that firing apart from a genuine local change and skip re-sending the
+    // peer's own value straight back to it.
+    let applying_inbound = Rc::new(Cell::new(false));
+
+    // Inbound: apply datagrams received from the remote peer to the local signal.
+    {
+        let on_error = Rc::clone(&on_error);
+        let applying_inbound = Rc::clone(&applying_inbound);
+
+        create_effect(move |_| {
+            let Some(bytes) = latest_datagram.get() else {
+                return;
+            };
+
+            match serde_json::from_slice::<T>(&bytes) {
+                Ok(new_value) => {
+                    if signal.with_untracked(|current| current != &new_value) {
+                        applying_inbound.set(true);
+                        signal.update(|current| *current = new_value);
+                        applying_inbound.set(false);
+                    }
+                }
+                Err(err) => on_error(format!("failed to decode datagram: {err}")),
+            }
+        });
+    }
+
+    // The watch's own immediate run below happens before the connection is open, when
+    // sending is a no-op, so the current value is sent again each time it opens.
+    if immediate {
+        let send_datagram = Rc::clone(&send_datagram);
+        let on_error = Rc::clone(&on_error);
+
+        create_effect(move |_| {
+            if ready_state.get() == ConnectionReadyState::Open {
+                match signal.with_untracked(|value| serde_json::to_vec(value)) {
+                    Ok(bytes) => (send_datagram)(&bytes),
+                    Err(err) => on_error(format!("failed to encode value: {err}")),
+                }
+            }
+        });
+    }
+
+    // Outbound: forward local changes to the remote peer as datagrams.
+    let stop_watch = watch(
+        move || signal.get(),
+        move |new_value, _, _| {
+            if applying_inbound.get() {
+                return;
+            }
+
+            match serde_json::to_vec(new_value) {
+                Ok(bytes) => (send_datagram)(&bytes),
+                Err(err) => on_error(format!("failed to encode value: {err}")),
+            }
+        },
+        immediate,
+    );
+
+    move || {
+        stop_watch();
+    }
+}
+
+/// Options for [`sync_signal_over_transport_with_options`].
+#[derive(DefaultBuilder)]
+pub struct SyncSignalOverTransportOptions {
+    /// If `true`, the local signal's current value is sent to the remote peer
+    /// as soon as the connection opens. Defaults to `true`.
+    immediate: bool,
+
+    /// Called whenever a datagram fails to encode or decode.
+    /// Defaults to a no-op.
+    #[builder(skip)]
+    on_error: Rc<dyn Fn(String)>,
+}
+
+impl SyncSignalOverTransportOptions {
+    /// Called whenever a datagram fails to encode or decode.
+    /// Defaults to a no-op.
+    pub fn on_error(self, on_error: impl Fn(String) + 'static) -> Self {
+        Self {
+            on_error: Rc::new(on_error),
+            ..self
+        }
+    }
+}
+
+impl Default for SyncSignalOverTransportOptions {
+    fn default() -> Self {
+        Self {
+            immediate: true,
+            on_error: Rc::new(|_| {}),
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use default_struct_builder::DefaultBuilder;
+use leptos::*;
+use serde::{Deserialize, Serialize};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Replicates an application state machine across clients the way an operation-based
+/// CRDT does, instead of syncing raw values like [`sync_signal`](crate::sync_signal) does.
+///
+/// You provide a `State`, an `Intent` (a user action), and a `reducer` that applies an
+/// intent to a state. `use_shared_state` gives back a read signal of the current state
+/// and a `dispatch` function. Calling `dispatch` applies the intent optimistically and
+/// hands it to [`UseSharedStateReturn::outbound`] so you can broadcast it to the other
+/// clients over whichever transport you like (e.g. [`use_webtransport`](crate::use_webtransport)
+/// or a websocket); when a remote intent comes back in, pass it to
+/// [`UseSharedStateReturn::apply_remote`].
+///
+/// To converge without central locking, every intent is tagged with a Lamport timestamp
+/// and a client id. A small ordered log of recently applied intents is kept alongside a
+/// checkpoint of the state before them; when an out-of-order intent arrives, the state is
+/// rolled back to the checkpoint and the log - now including the new intent - is replayed
+/// in `(timestamp, client_id)` order, so peers that have seen the same intents reach the
+/// same deterministic state. Only the intents still in the log are reordered: once intents
+/// have been folded into the checkpoint (see `checkpoint_interval` and `replay_depth`), an
+/// intent that arrives later but sorts before them is applied after them.
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_shared_state;
+/// #
+/// #[derive(Clone, Default, serde::Serialize, serde::Deserialize)]
+/// struct Counter(i32);
+///
+/// #[derive(Clone, serde::Serialize, serde::Deserialize)]
+/// enum CounterIntent {
+///     Increment,
+///     Decrement,
+/// }
+///
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let shared = use_shared_state(Counter::default(), |state: &mut Counter, intent| {
+///     match intent {
+///         CounterIntent::Increment => state.0 += 1,
+///         CounterIntent::Decrement => state.0 -= 1,
+///     }
+/// });
+///
+/// (shared.dispatch)(CounterIntent::Increment);
+/// #
+/// # view! { }
+/// # }
+/// ```
+pub fn use_shared_state<S, I>(
+    initial: S,
+    reducer: impl Fn(&mut S, &I) + Clone + 'static,
+) -> UseSharedStateReturn<S, I>
+where
+    S: Clone + 'static,
+    I: Clone + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    use_shared_state_with_options(initial, reducer, UseSharedStateOptions::default())
+}
+
+/// Version of [`use_shared_state`] that takes a `UseSharedStateOptions`. See
+/// [`use_shared_state`] for how to use.
+pub fn use_shared_state_with_options<S, I>(
+    initial: S,
+    reducer: impl Fn(&mut S, &I) + Clone + 'static,
+    options: UseSharedStateOptions,
+) -> UseSharedStateReturn<S, I>
+where
+    S: Clone + 'static,
+    I: Clone + Serialize + for<'de> Deserialize<'de> + 'static,
+{
+    let UseSharedStateOptions {
+        client_id,
+        replay_depth,
+        checkpoint_interval,
+    } = options;
+
+    let (state, set_state) = create_signal(initial.clone());
+    let (outbound, set_outbound) = create_signal(None::<TimestampedIntent<I>>);
+
+    let log = Rc::new(RefCell::new(SharedStateLog {
+        checkpoint: initial,
+        entries: Vec::new(),
+        clock: 0,
+    }));
+
+    let apply_remote = {
+        let log = Rc::clone(&log);
+        let reducer = reducer.clone();
+
+        Rc::new(move |intent: TimestampedIntent<I>| {
+            // A fan-out transport may echo our own dispatch back to us; applying it again
+            // would double its effect, so self-originated intents and intents still in the
+            // log are both dropped here. One already folded into the checkpoint is not
+            // recognised as seen.
+            if intent.client_id == client_id {
+                return;
+            }
+
+            let mut log = log.borrow_mut();
+
+            if log
+                .entries
+                .iter()
+                .any(|existing| order_key(existing) == order_key(&intent))
+            {
+                return;
+            }
+
+            log.clock = log.clock.max(intent.timestamp).saturating_add(1);
+
+            let insert_at = log
+                .entries
+                .iter()
+                .position(|existing| order_key(existing) > order_key(&intent))
+                .unwrap_or(log.entries.len());
+            let needs_replay = insert_at != log.entries.len();
+            log.entries.insert(insert_at, intent);
+
+            if needs_replay {
+                replay(&mut log, &reducer, set_state);
+            } else {
+                set_state.update(|state| reducer(state, &log.entries.last().unwrap().intent));
+            }
+
+            log.maybe_checkpoint(checkpoint_interval, &reducer, set_state);
+            log.trim(replay_depth, &reducer);
+        })
+    };
+
+    let dispatch = {
+        let log = Rc::clone(&log);
+
+        Rc::new(move |intent: I| {
+            let mut log = log.borrow_mut();
+            log.clock += 1;
+
+            let timestamped = TimestampedIntent {
+                timestamp: log.clock,
+                client_id,
+                intent,
+            };
+            log.entries.push(timestamped.clone());
+
+            set_state.update(|state| reducer(state, &timestamped.intent));
+            log.maybe_checkpoint(checkpoint_interval, &reducer, set_state);
+            log.trim(replay_depth, &reducer);
+
+            set_outbound.set(Some(timestamped));
+        })
+    };
+
+    UseSharedStateReturn {
+        state: state.into(),
+        dispatch,
+        apply_remote,
+        outbound: outbound.into(),
+    }
+}
+
+fn order_key<I>(entry: &TimestampedIntent<I>) -> (u64, u64) {
+    (entry.timestamp, entry.client_id)
+}
+
+fn replay<S, I>(
+    log: &mut SharedStateLog<S, I>,
+    reducer: &(impl Fn(&mut S, &I) + Clone + 'static),
+    set_state: WriteSignal<S>,
+) where
+    S: Clone + 'static,
+{
+    log.entries.sort_by_key(order_key);
+
+    let mut state = log.checkpoint.clone();
+    for entry in &log.entries {
+        reducer(&mut state, &entry.intent);
+    }
+    set_state.set(state);
+}
+
+struct SharedStateLog<S, I> {
+    checkpoint: S,
+    entries: Vec<TimestampedIntent<I>>,
+    clock: u64,
+}
+
+impl<S, I> SharedStateLog<S, I>
+where
+    S: Clone + 'static,
+{
+    fn maybe_checkpoint(
+        &mut self,
+        checkpoint_interval: usize,
+        reducer: &(impl Fn(&mut S, &I) + Clone + 'static),
+        set_state: WriteSignal<S>,
+    ) {
+        if self.entries.len() < checkpoint_interval {
+            return;
+        }
+
+        let mut state = self.checkpoint.clone();
+        for entry in &self.entries {
+            reducer(&mut state, &entry.intent);
+        }
+
+        self.checkpoint = state.clone();
+        self.entries.clear();
+        set_state.set(state);
+    }
+
+    /// Drops the oldest entries beyond `replay_depth`, folding each one into `checkpoint`
+    /// first so the state a future replay starts from still accounts for them.
+    fn trim(&mut self, replay_depth: usize, reducer: &(impl Fn(&mut S, &I) + Clone + 'static)) {
+        let overflow = self.entries.len().saturating_sub(replay_depth);
+        if overflow == 0 {
+            return;
+        }
+
+        let mut checkpoint = self.checkpoint.clone();
+        for entry in self.entries.drain(0..overflow) {
+            reducer(&mut checkpoint, &entry.intent);
+        }
+        self.checkpoint = checkpoint;
+    }
+}
+
+/// An intent tagged with the Lamport timestamp and client id it was dispatched with.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct TimestampedIntent<I> {
+    /// Lamport timestamp of the client that dispatched this intent.
+    pub timestamp: u64,
+    /// Id of the client that dispatched this intent.
+    pub client_id: u64,
+    /// The intent itself.
+    pub intent: I,
+}
+
+/// Options for [`use_shared_state_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseSharedStateOptions {
+    /// Id used to break ties between intents with the same Lamport timestamp.
+    /// Defaults to a random `u64` (`0` with the `ssr` feature).
+    client_id: u64,
+
+    /// How many of the most recent intents are kept around to be replayed when an
+    /// out-of-order intent arrives. Defaults to `64`.
+    replay_depth: usize,
+
+    /// How many intents accumulate before they're folded into a new checkpoint.
+    /// Defaults to `32`.
+    checkpoint_interval: usize,
+}
+
+impl Default for UseSharedStateOptions {
+    fn default() -> Self {
+        Self {
+            client_id: random_client_id(),
+            replay_depth: 64,
+            checkpoint_interval: 32,
+        }
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn random_client_id() -> u64 {
+    (js_sys::Math::random() * u64::MAX as f64) as u64
+}
+
+#[cfg(feature = "ssr")]
+fn random_client_id() -> u64 {
+    0
+}
+
+/// Return type of [`use_shared_state`].
+pub struct UseSharedStateReturn<S, I>
+where
+    S: 'static,
+    I: 'static,
+{
+    /// The current, converged state.
+    pub state: Signal<S>,
+
+    /// Applies an intent optimistically and queues it on [`Self::outbound`] for broadcast.
+    pub dispatch: Rc<dyn Fn(I)>,
+
+    /// Applies an intent received from a remote client, rolling back to the last
+    /// checkpoint and replaying the log if it arrived out of order.
+    pub apply_remote: Rc<dyn Fn(TimestampedIntent<I>)>,
+
+    /// Emits every intent dispatched locally so it can be forwarded to other clients.
+    pub outbound: Signal<Option<TimestampedIntent<I>>>,
+}
+
+impl<S, I> Clone for UseSharedStateReturn<S, I> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state,
+            dispatch: Rc::clone(&self.dispatch),
+            apply_remote: Rc::clone(&self.apply_remote),
+            outbound: self.outbound,
+        }
+    }
+}
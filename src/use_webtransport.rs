@@ -0,0 +1,431 @@
+use crate::core::ConnectionReadyState;
+use default_struct_builder::DefaultBuilder;
+use gloo_timers::future::TimeoutFuture;
+use leptos::*;
+use std::cell::{Cell, RefCell};
+use std::rc::{Rc, Weak};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
+
+/// Reactive [WebTransport](https://developer.mozilla.org/en-US/docs/Web/API/WebTransport) API.
+///
+/// Opens a WebTransport session to `url` and exposes its connection state as a
+/// reactive signal, a way to send and receive datagrams, and methods to open
+/// unidirectional and bidirectional streams.
+///
+/// ## Demo
+///
+/// [Link to Demo](https://github.com/Synphonyte/leptos-use/tree/main/examples/use_webtransport)
+///
+/// ## Usage
+///
+/// ```
+/// # use leptos::*;
+/// # use leptos_use::use_webtransport;
+/// #
+/// # #[component]
+/// # fn Demo() -> impl IntoView {
+/// let transport = use_webtransport("https://example.com:4433");
+///
+/// let send = {
+///     let transport = transport.clone();
+///     move |_| (transport.send_datagram)(b"ping")
+/// };
+///
+/// view! {
+///     <div>"State: " {move || format!("{:?}", transport.ready_state.get())}</div>
+///     <button on:click=send>"Send"</button>
+/// }
+/// # }
+/// ```
+pub fn use_webtransport(url: &str) -> UseWebTransportReturn {
+    use_webtransport_with_options(url, UseWebTransportOptions::default())
+}
+
+/// Version of [`use_webtransport`] that takes a `UseWebTransportOptions`. See [`use_webtransport`] for how to use.
+pub fn use_webtransport_with_options(
+    url: &str,
+    options: UseWebTransportOptions,
+) -> UseWebTransportReturn {
+    let UseWebTransportOptions {
+        reconnect_limit,
+        reconnect_interval,
+        immediate,
+        codec,
+        on_open,
+        on_close,
+        on_error,
+    } = options;
+
+    let (ready_state, set_ready_state) = create_signal(ConnectionReadyState::Closed);
+    let (latest_datagram, set_latest_datagram) = create_signal(None::<Vec<u8>>);
+    let transport = Rc::new(RefCell::new(None::<Rc<web_sys::WebTransport>>));
+    let reconnect_attempt = Rc::new(Cell::new(0u64));
+
+    let url = url.to_string();
+
+    // `connect` calls itself again (through a weak reference, so the hook doesn't leak a
+    // reference cycle) when the session drops and the reconnect budget isn't spent yet.
+    let connect_slot: Rc<RefCell<Option<Rc<dyn Fn()>>>> = Rc::new(RefCell::new(None));
+
+    // `connect` below is dropped when this function returns; keep a strong reference with
+    // the reactive owner so the weak reference used for reconnecting stays alive.
+    {
+        let connect_slot = Rc::clone(&connect_slot);
+        on_cleanup(move || drop(connect_slot));
+    }
+
+    {
+        let connect_weak = Rc::downgrade(&connect_slot);
+        let transport = Rc::clone(&transport);
+        let reconnect_attempt = Rc::clone(&reconnect_attempt);
+        let on_error = Rc::clone(&on_error);
+
+        let connect_impl: Rc<dyn Fn()> = Rc::new(move || {
+            let url = url.clone();
+            let transport_slot = Rc::clone(&transport);
+            let connect_weak = connect_weak.clone();
+            let reconnect_attempt = Rc::clone(&reconnect_attempt);
+            let on_open = Rc::clone(&on_open);
+            let on_close = Rc::clone(&on_close);
+            let on_error = Rc::clone(&on_error);
+
+            set_ready_state.set(ConnectionReadyState::Connecting);
+
+            spawn_local(async move {
+                let new_transport = match web_sys::WebTransport::new(&url) {
+                    Ok(new_transport) => Rc::new(new_transport),
+                    Err(err) => {
+                        on_error(format!("failed to create WebTransport: {err:?}"));
+                        reconnect_or_close(
+                            &connect_weak,
+                            &reconnect_attempt,
+                            reconnect_limit,
+                            reconnect_interval,
+                            set_ready_state,
+                            &on_close,
+                        )
+                        .await;
+                        return;
+                    }
+                };
+
+                if JsFuture::from(new_transport.ready()).await.is_err() {
+                    on_error("WebTransport connection failed".to_string());
+                    reconnect_or_close(
+                        &connect_weak,
+                        &reconnect_attempt,
+                        reconnect_limit,
+                        reconnect_interval,
+                        set_ready_state,
+                        &on_close,
+                    )
+                    .await;
+                    return;
+                }
+
+                *transport_slot.borrow_mut() = Some(Rc::clone(&new_transport));
+                reconnect_attempt.set(0);
+                set_ready_state.set(ConnectionReadyState::Open);
+                on_open();
+
+                // Read incoming datagrams until the session closes.
+                let reader: web_sys::ReadableStreamDefaultReader = new_transport
+                    .datagrams()
+                    .readable()
+                    .get_reader()
+                    .unchecked_into();
+
+                loop {
+                    match JsFuture::from(reader.read()).await {
+                        Ok(result) => {
+                            let done = js_sys::Reflect::get(&result, &JsValue::from_str("done"))
+                                .map(|value| value.is_truthy())
+                                .unwrap_or(true);
+
+                            if done {
+                                break;
+                            }
+
+                            if let Ok(value) =
+                                js_sys::Reflect::get(&result, &JsValue::from_str("value"))
+                            {
+                                let bytes = js_sys::Uint8Array::new(&value).to_vec();
+
+                                match codec {
+                                    WebTransportCodec::Binary => {
+                                        set_latest_datagram.set(Some(bytes));
+                                    }
+                                    WebTransportCodec::Text => {
+                                        if std::str::from_utf8(&bytes).is_ok() {
+                                            set_latest_datagram.set(Some(bytes));
+                                        } else {
+                                            on_error(
+                                                "received a non-UTF-8 datagram with the Text codec"
+                                                    .to_string(),
+                                            );
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        Err(err) => {
+                            on_error(format!("failed to read datagram: {err:?}"));
+                            break;
+                        }
+                    }
+                }
+
+                *transport_slot.borrow_mut() = None;
+                reconnect_or_close(
+                    &connect_weak,
+                    &reconnect_attempt,
+                    reconnect_limit,
+                    reconnect_interval,
+                    set_ready_state,
+                    &on_close,
+                )
+                .await;
+            });
+        });
+
+        *connect_slot.borrow_mut() = Some(connect_impl);
+    }
+
+    let connect = move || {
+        if let Some(connect) = connect_slot.borrow().clone() {
+            connect();
+        }
+    };
+
+    if immediate {
+        connect();
+    }
+
+    let send_datagram = {
+        let transport = Rc::clone(&transport);
+
+        Rc::new(move |bytes: &[u8]| {
+            let Some(transport) = transport.borrow().clone() else {
+                return;
+            };
+            let chunk = js_sys::Uint8Array::from(bytes);
+            let on_error = Rc::clone(&on_error);
+
+            spawn_local(async move {
+                let writer: web_sys::WritableStreamDefaultWriter = transport
+                    .datagrams()
+                    .writable()
+                    .get_writer()
+                    .unchecked_into();
+                if let Err(err) = JsFuture::from(writer.write_with_chunk(&chunk)).await {
+                    on_error(format!("failed to send datagram: {err:?}"));
+                }
+                writer.release_lock();
+            });
+        })
+    };
+
+    let open_uni = {
+        let transport = Rc::clone(&transport);
+
+        Rc::new(move || {
+            let transport = transport.borrow().clone();
+
+            Box::pin(async move {
+                let transport = transport.ok_or_else(|| "not connected".to_string())?;
+                let writable = JsFuture::from(transport.create_unidirectional_stream())
+                    .await
+                    .map_err(|err| format!("{err:?}"))?;
+
+                Ok(writable.unchecked_into::<web_sys::WritableStream>())
+            })
+                as std::pin::Pin<
+                    Box<dyn std::future::Future<Output = Result<web_sys::WritableStream, String>>>,
+                >
+        })
+    };
+
+    let open_bi = {
+        let transport = Rc::clone(&transport);
+
+        Rc::new(move || {
+            let transport = transport.borrow().clone();
+
+            Box::pin(async move {
+                let transport = transport.ok_or_else(|| "not connected".to_string())?;
+                let stream = JsFuture::from(transport.create_bidirectional_stream())
+                    .await
+                    .map_err(|err| format!("{err:?}"))?;
+                let stream: web_sys::WebTransportBidirectionalStream = stream.unchecked_into();
+
+                Ok((stream.readable(), stream.writable()))
+            })
+                as std::pin::Pin<
+                    Box<
+                        dyn std::future::Future<
+                            Output = Result<
+                                (web_sys::ReadableStream, web_sys::WritableStream),
+                                String,
+                            >,
+                        >,
+                    >,
+                >
+        })
+    };
+
+    UseWebTransportReturn {
+        ready_state: ready_state.into(),
+        latest_datagram: latest_datagram.into(),
+        send_datagram,
+        open_uni,
+        open_bi,
+    }
+}
+
+/// Waits out the backoff (`reconnect_interval`, doubled for every earlier attempt) and
+/// retries through `connect` while the reconnect budget isn't spent; otherwise settles the
+/// session as closed.
+async fn reconnect_or_close(
+    connect: &Weak<RefCell<Option<Rc<dyn Fn()>>>>,
+    reconnect_attempt: &Rc<Cell<u64>>,
+    reconnect_limit: u64,
+    reconnect_interval: u64,
+    set_ready_state: WriteSignal<ConnectionReadyState>,
+    on_close: &Rc<dyn Fn()>,
+) {
+    let attempt = reconnect_attempt.get();
+
+    if attempt < reconnect_limit {
+        reconnect_attempt.set(attempt + 1);
+
+        // `2u64.pow` panics once the exponent reaches 64, and `reconnect_limit` is an
+        // unbounded user-set `u64` - cap the backoff at something already far longer than
+        // any reconnect would sanely wait, and saturate instead of overflowing on the way
+        // to a `u32` delay.
+        let backoff = 2u64.checked_pow(attempt.min(62) as u32).unwrap_or(u64::MAX);
+        let delay_ms = reconnect_interval
+            .saturating_mul(backoff)
+            .min(u32::MAX as u64);
+        TimeoutFuture::new(delay_ms as u32).await;
+
+        if let Some(connect) = connect.upgrade().and_then(|slot| slot.borrow().clone()) {
+            connect();
+            return;
+        }
+    }
+
+    set_ready_state.set(ConnectionReadyState::Closed);
+    on_close();
+}
+
+/// How datagram payloads are interpreted when exposed outside this hook.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum WebTransportCodec {
+    /// Datagrams are passed through as raw bytes.
+    #[default]
+    Binary,
+    /// Datagrams are required to be valid UTF-8; anything else is dropped and reported
+    /// through `on_error`.
+    Text,
+}
+
+/// Options for [`use_webtransport_with_options`].
+#[derive(DefaultBuilder)]
+pub struct UseWebTransportOptions {
+    /// How many times a dropped connection is retried before giving up. Defaults to 3.
+    reconnect_limit: u64,
+
+    /// Milliseconds to wait before the first reconnect attempt; doubles after every
+    /// further attempt. Defaults to 1000.
+    reconnect_interval: u64,
+
+    /// If `true`, the connection is opened immediately. Defaults to `true`.
+    immediate: bool,
+
+    /// Whether datagrams are treated as binary or text. Defaults to `Binary`.
+    codec: WebTransportCodec,
+
+    /// Called when the session is established.
+    #[builder(skip)]
+    on_open: Rc<dyn Fn()>,
+
+    /// Called when the session closes and the reconnect budget has been spent.
+    #[builder(skip)]
+    on_close: Rc<dyn Fn()>,
+
+    /// Called whenever connecting, sending, or receiving fails.
+    #[builder(skip)]
+    on_error: Rc<dyn Fn(String)>,
+}
+
+impl UseWebTransportOptions {
+    /// Called when the session is established.
+    pub fn on_open(self, on_open: impl Fn() + 'static) -> Self {
+        Self {
+            on_open: Rc::new(on_open),
+            ..self
+        }
+    }
+
+    /// Called when the session closes and the reconnect budget has been spent.
+    pub fn on_close(self, on_close: impl Fn() + 'static) -> Self {
+        Self {
+            on_close: Rc::new(on_close),
+            ..self
+        }
+    }
+
+    /// Called whenever connecting, sending, or receiving fails.
+    pub fn on_error(self, on_error: impl Fn(String) + 'static) -> Self {
+        Self {
+            on_error: Rc::new(on_error),
+            ..self
+        }
+    }
+}
+
+impl Default for UseWebTransportOptions {
+    fn default() -> Self {
+        Self {
+            reconnect_limit: 3,
+            reconnect_interval: 1000,
+            immediate: true,
+            codec: WebTransportCodec::default(),
+            on_open: Rc::new(|| {}),
+            on_close: Rc::new(|| {}),
+            on_error: Rc::new(|_| {}),
+        }
+    }
+}
+
+/// Return type of [`use_webtransport`].
+#[derive(Clone)]
+pub struct UseWebTransportReturn {
+    /// The current state of the WebTransport session.
+    pub ready_state: Signal<ConnectionReadyState>,
+
+    /// The most recently received datagram, if any.
+    pub latest_datagram: Signal<Option<Vec<u8>>>,
+
+    /// Sends a datagram over the session. A no-op while not connected.
+    pub send_datagram: Rc<dyn Fn(&[u8])>,
+
+    /// Opens a unidirectional stream and resolves to its writable side.
+    pub open_uni: Rc<
+        dyn Fn() -> std::pin::Pin<
+            Box<dyn std::future::Future<Output = Result<web_sys::WritableStream, String>>>,
+        >,
+    >,
+
+    /// Opens a bidirectional stream and resolves to its readable/writable pair.
+    pub open_bi: Rc<
+        dyn Fn() -> std::pin::Pin<
+            Box<
+                dyn std::future::Future<
+                    Output = Result<(web_sys::ReadableStream, web_sys::WritableStream), String>,
+                >,
+            >,
+        >,
+    >,
+}